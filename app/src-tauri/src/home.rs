@@ -0,0 +1,110 @@
+//! Home directory resolution shared by every path the app derives from `~`.
+//!
+//! `dirs::home_dir()` trusts `$HOME` whenever it is set, so a relative or
+//! otherwise unusable value never reaches its passwd fallback. All call sites
+//! go through this chain instead: a non-empty, absolute `$HOME`, then the
+//! passwd entry for the current uid.
+
+use std::env;
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+pub const HOME_DIR_UNAVAILABLE: &str =
+    "Cannot determine home directory (HOME is unset and no passwd entry was found)";
+
+/// Returns the current user's home directory or [`HOME_DIR_UNAVAILABLE`].
+pub fn home_dir() -> Result<PathBuf, String> {
+    resolve_home_dir(env::var_os("HOME"), passwd_home_dir)
+}
+
+fn resolve_home_dir(
+    home_env: Option<OsString>,
+    passwd_home: impl FnOnce() -> Option<PathBuf>,
+) -> Result<PathBuf, String> {
+    home_env
+        .map(PathBuf::from)
+        .filter(|home| home.is_absolute())
+        .or_else(|| passwd_home().filter(|home| home.is_absolute()))
+        .ok_or_else(|| HOME_DIR_UNAVAILABLE.to_string())
+}
+
+/// Looks up the current uid with `getpwuid_r`, starting from the
+/// `_SC_GETPW_R_SIZE_MAX` hint and doubling the buffer on `ERANGE`.
+#[cfg(unix)]
+fn passwd_home_dir() -> Option<PathBuf> {
+    use std::ffi::{CStr, OsStr};
+    use std::os::unix::ffi::OsStrExt;
+
+    const MAX_BUFFER: usize = 1 << 20;
+
+    let hint = unsafe { libc::sysconf(libc::_SC_GETPW_R_SIZE_MAX) };
+    let mut len = usize::try_from(hint)
+        .ok()
+        .filter(|len| *len > 0)
+        .unwrap_or(1024);
+    loop {
+        let mut buf: Vec<libc::c_char> = vec![0; len];
+        let mut passwd: libc::passwd = unsafe { std::mem::zeroed() };
+        let mut result: *mut libc::passwd = std::ptr::null_mut();
+        let rc = unsafe {
+            libc::getpwuid_r(
+                libc::getuid(),
+                &mut passwd,
+                buf.as_mut_ptr(),
+                buf.len(),
+                &mut result,
+            )
+        };
+        if rc == libc::ERANGE && len < MAX_BUFFER {
+            len *= 2;
+            continue;
+        }
+        if rc != 0 || result.is_null() || passwd.pw_dir.is_null() {
+            return None;
+        }
+        let dir = unsafe { CStr::from_ptr(passwd.pw_dir) };
+        return Some(PathBuf::from(OsStr::from_bytes(dir.to_bytes())));
+    }
+}
+
+#[cfg(not(unix))]
+fn passwd_home_dir() -> Option<PathBuf> {
+    dirs::home_dir()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_home_dir_prefers_home_env() {
+        assert_eq!(
+            resolve_home_dir(Some(OsString::from("/Users/attn")), || {
+                Some(PathBuf::from("/Users/passwd"))
+            }),
+            Ok(PathBuf::from("/Users/attn"))
+        );
+    }
+
+    #[test]
+    fn resolve_home_dir_falls_back_to_passwd_when_home_is_unusable() {
+        for home_env in [
+            None,
+            Some(OsString::new()),
+            Some(OsString::from("relative")),
+        ] {
+            assert_eq!(
+                resolve_home_dir(home_env, || Some(PathBuf::from("/Users/passwd"))),
+                Ok(PathBuf::from("/Users/passwd"))
+            );
+        }
+    }
+
+    #[test]
+    fn resolve_home_dir_errors_without_home_or_passwd_entry() {
+        assert_eq!(
+            resolve_home_dir(None, || None),
+            Err(HOME_DIR_UNAVAILABLE.to_string())
+        );
+    }
+}
@@ -1,6 +1,9 @@
 mod browser_alerts;
 mod browser_host;
+mod home;
 mod profile;
+#[cfg(test)]
+mod test_support;
 mod ui_automation;
 
 use std::env;
@@ -22,7 +25,7 @@ fn daemon_socket_path() -> Option<PathBuf> {
         }
     }
 
-    let home = dirs::home_dir()?;
+    let home = home::home_dir().ok()?;
     let data_dir = match profile::build_profile() {
         "" => ".attn".to_string(),
         named => format!(".attn-{named}"),
@@ -205,15 +208,21 @@ fn wait_for_daemon_shutdown(socket_path: &Path, timeout: Duration) -> bool {
 const DAEMON_START_TIMEOUT: Duration = Duration::from_secs(10);
 const DAEMON_ENSURE_TIMEOUT: Duration = Duration::from_secs(20);
 
+/// True only when the daemon socket accepts a connection and `/health`
+/// reports "ok"; a socket file left behind by a crashed daemon fails the
+/// connect and reads as not running.
+fn daemon_is_healthy_at(socket_path: &Path) -> bool {
+    daemon_is_running_at(socket_path)
+        && daemon_http_is_live(Duration::from_millis(250))
+        && fetch_daemon_health(Duration::from_millis(500))
+            .is_ok_and(|health| health.status.trim() == "ok")
+}
+
 fn wait_for_daemon_health(socket_path: &Path, timeout: Duration) -> bool {
     let deadline = Instant::now() + timeout;
     loop {
-        if daemon_is_running_at(socket_path) && daemon_http_is_live(Duration::from_millis(250)) {
-            if let Ok(health) = fetch_daemon_health(Duration::from_millis(500)) {
-                if health.status.trim() == "ok" {
-                    return true;
-                }
-            }
+        if daemon_is_healthy_at(socket_path) {
+            return true;
         }
         if Instant::now() >= deadline {
             return false;
@@ -502,6 +511,40 @@ fn ensure_daemon(_app: tauri::AppHandle) -> Result<(), String> {
     }
 }
 
+#[tauri::command]
+async fn daemon_ping() -> Result<bool, String> {
+    let socket_path = daemon_socket_path().ok_or("Cannot resolve daemon socket path")?;
+    Ok(daemon_is_healthy_at(&socket_path))
+}
+
+#[cfg(test)]
+mod daemon_ping_tests {
+    use super::*;
+    use crate::test_support::temp_path;
+    use std::fs;
+
+    #[test]
+    fn daemon_is_healthy_at_rejects_missing_socket() {
+        let socket_path = temp_path("missing", "sock");
+
+        assert!(!daemon_is_healthy_at(&socket_path));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn daemon_is_healthy_at_rejects_stale_socket_file() {
+        use std::os::unix::net::UnixListener;
+
+        let socket_path = temp_path("stale", "sock");
+        drop(UnixListener::bind(&socket_path).expect("bind socket"));
+        assert!(socket_path.exists());
+
+        assert!(!daemon_is_healthy_at(&socket_path));
+
+        fs::remove_file(socket_path).expect("remove socket");
+    }
+}
+
 #[tauri::command]
 fn quit_app(app: tauri::AppHandle) {
     app.exit(0);
@@ -671,16 +714,130 @@ fn open_presentation_window(
     .map_err(|error| format!("create presentation window: {error}"))
 }
 
+fn grid_cells(pixels: u32, cell: u32) -> u16 {
+    if cell == 0 {
+        return 1;
+    }
+    (pixels / cell).clamp(1, u16::MAX as u32) as u16
+}
+
+/// Converts a pixel viewport into whole terminal cells (cols, rows).
+/// Partial cells are dropped, both axes are clamped to at least one cell,
+/// and a zero cell size yields the minimum instead of dividing by zero.
+#[tauri::command]
+fn compute_grid(pixel_w: u32, pixel_h: u32, cell_w: u32, cell_h: u32) -> (u16, u16) {
+    (grid_cells(pixel_w, cell_w), grid_cells(pixel_h, cell_h))
+}
+
+#[cfg(test)]
+mod compute_grid_tests {
+    use super::*;
+
+    #[test]
+    fn compute_grid_drops_partial_cells() {
+        assert_eq!(compute_grid(800, 600, 8, 16), (100, 37));
+        assert_eq!(compute_grid(807, 615, 8, 16), (100, 38));
+    }
+
+    #[test]
+    fn compute_grid_clamps_to_one_cell() {
+        assert_eq!(compute_grid(0, 0, 8, 16), (1, 1));
+        assert_eq!(compute_grid(7, 15, 8, 16), (1, 1));
+    }
+
+    #[test]
+    fn compute_grid_guards_zero_cell_size() {
+        assert_eq!(compute_grid(800, 600, 0, 0), (1, 1));
+        assert_eq!(compute_grid(800, 600, 8, 0), (100, 1));
+    }
+
+    #[test]
+    fn compute_grid_saturates_at_u16_max() {
+        assert_eq!(compute_grid(u32::MAX, u32::MAX, 1, 1), (u16::MAX, u16::MAX));
+    }
+}
+
+const SESSION_COLOR_SATURATION: f64 = 0.65;
+const SESSION_COLOR_LIGHTNESS: f64 = 0.55;
+
+// FNV-1a keeps the hue stable across platforms and releases, unlike
+// std's randomly seeded hasher.
+fn session_hue(id: &str) -> u16 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in id.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    (hash % 360) as u16
+}
+
+fn hsl_to_hex(hue: u16, saturation: f64, lightness: f64) -> String {
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let sector = hue as f64 / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (r, g, b) = match sector as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = lightness - chroma / 2.0;
+    let channel = |value: f64| ((value + m) * 255.0).round() as u8;
+    format!("#{:02x}{:02x}{:02x}", channel(r), channel(g), channel(b))
+}
+
+/// Deterministic hex color for a session id. Saturation and lightness are
+/// fixed so every session stays readable on both themes; only the hue varies.
+#[tauri::command]
+fn session_color(id: String) -> String {
+    hsl_to_hex(
+        session_hue(&id),
+        SESSION_COLOR_SATURATION,
+        SESSION_COLOR_LIGHTNESS,
+    )
+}
+
+#[cfg(test)]
+mod session_color_tests {
+    use super::*;
+
+    #[test]
+    fn session_color_is_stable_for_an_id() {
+        let first = session_color("sess-1234".to_string());
+        assert_eq!(first, session_color("sess-1234".to_string()));
+        assert_eq!(first.len(), 7);
+        assert!(first.starts_with('#'));
+    }
+
+    #[test]
+    fn session_color_spreads_ids_across_the_hue_wheel() {
+        let mut sectors = [false; 12];
+        for index in 0..64 {
+            sectors[(session_hue(&format!("session-{index}")) / 30) as usize] = true;
+        }
+        assert!(sectors.iter().filter(|hit| **hit).count() >= 10);
+    }
+
+    #[test]
+    fn hsl_to_hex_matches_primary_hues() {
+        assert_eq!(hsl_to_hex(0, 1.0, 0.5), "#ff0000");
+        assert_eq!(hsl_to_hex(120, 1.0, 0.5), "#00ff00");
+        assert_eq!(hsl_to_hex(240, 1.0, 0.5), "#0000ff");
+    }
+}
+
 #[tauri::command]
 async fn list_directory(path: String, prefix: Option<String>) -> Result<Vec<String>, String> {
     use std::fs;
     use std::path::Path;
 
     let dir_path = if let Some(suffix) = path.strip_prefix("~/") {
-        let home = dirs::home_dir().ok_or("Cannot get home directory")?;
+        let home = home::home_dir()?;
         home.join(suffix)
     } else if path == "~" {
-        dirs::home_dir().ok_or("Cannot get home directory")?
+        home::home_dir()?
     } else {
         Path::new(&path).to_path_buf()
     };
@@ -729,6 +886,201 @@ async fn list_directory(path: String, prefix: Option<String>) -> Result<Vec<Stri
     Ok(directories)
 }
 
+/// Walks up from `path` until a directory containing `.git` is found. A `.git`
+/// file (linked worktree or submodule) counts as well, so the returned root is
+/// the checkout the user is in rather than the main repository.
+fn find_git_root(path: &Path) -> Result<Option<PathBuf>, String> {
+    let start = std::fs::canonicalize(path)
+        .map_err(|e| format!("Cannot resolve {}: {}", path.display(), e))?;
+    let start = if start.is_dir() {
+        start
+    } else {
+        match start.parent() {
+            Some(parent) => parent.to_path_buf(),
+            None => return Ok(None),
+        }
+    };
+
+    for dir in start.ancestors() {
+        let marker = dir.join(".git");
+        if marker.is_dir() || marker.is_file() {
+            return Ok(Some(dir.to_path_buf()));
+        }
+    }
+    Ok(None)
+}
+
+#[tauri::command]
+fn is_git_repo(path: String) -> Result<Option<String>, String> {
+    Ok(find_git_root(Path::new(&path))?.map(|root| root.to_string_lossy().to_string()))
+}
+
+#[cfg(test)]
+mod git_repo_tests {
+    use super::*;
+    use crate::test_support::temp_dir;
+    use std::fs;
+
+    #[test]
+    fn find_git_root_returns_repo_root() {
+        let dir = temp_dir("git-root-repo");
+        fs::create_dir(dir.join(".git")).expect("create .git");
+
+        assert_eq!(find_git_root(&dir).expect("probe"), Some(dir.clone()));
+
+        fs::remove_dir_all(dir).expect("remove temp dir");
+    }
+
+    #[test]
+    fn find_git_root_walks_up_from_subdirectory() {
+        let dir = temp_dir("git-root-subdir");
+        fs::create_dir(dir.join(".git")).expect("create .git");
+        let nested = dir.join("src").join("nested");
+        fs::create_dir_all(&nested).expect("create nested");
+
+        assert_eq!(find_git_root(&nested).expect("probe"), Some(dir.clone()));
+
+        fs::remove_dir_all(dir).expect("remove temp dir");
+    }
+
+    #[test]
+    fn find_git_root_accepts_worktree_git_file() {
+        let dir = temp_dir("git-root-worktree");
+        fs::write(
+            dir.join(".git"),
+            "gitdir: /elsewhere/.git/worktrees/feature\n",
+        )
+        .expect("write .git file");
+
+        assert_eq!(find_git_root(&dir).expect("probe"), Some(dir.clone()));
+
+        fs::remove_dir_all(dir).expect("remove temp dir");
+    }
+
+    #[test]
+    fn find_git_root_returns_none_outside_repo() {
+        let dir = temp_dir("git-root-none");
+
+        assert_eq!(find_git_root(&dir).expect("probe"), None);
+
+        fs::remove_dir_all(dir).expect("remove temp dir");
+    }
+
+    #[test]
+    fn find_git_root_rejects_missing_path() {
+        let dir = temp_dir("git-root-missing");
+
+        assert!(find_git_root(&dir.join("does-not-exist")).is_err());
+
+        fs::remove_dir_all(dir).expect("remove temp dir");
+    }
+}
+
+const MAX_RESOLVE_ROOTS: usize = 16;
+
+/// Returns every existing absolute location `value` can refer to. Relative
+/// values are joined against each root in order (session cwd, git root, ...);
+/// duplicates that canonicalize to the same file are dropped. Only the first
+/// `MAX_RESOLVE_ROOTS` roots are probed so a long list can't stat the disk
+/// unbounded.
+fn resolve_candidates(value: &str, roots: &[String]) -> Vec<PathBuf> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Vec::new();
+    }
+    let path = Path::new(value);
+    if path.is_absolute() {
+        return std::fs::canonicalize(path).into_iter().collect();
+    }
+
+    let mut candidates: Vec<PathBuf> = Vec::new();
+    for root in roots
+        .iter()
+        .map(|root| root.trim())
+        .filter(|root| !root.is_empty())
+        .take(MAX_RESOLVE_ROOTS)
+    {
+        let Ok(candidate) = std::fs::canonicalize(Path::new(root).join(path)) else {
+            continue;
+        };
+        if !candidates.contains(&candidate) {
+            candidates.push(candidate);
+        }
+    }
+    candidates
+}
+
+#[tauri::command]
+fn resolve_path(value: String, roots: Vec<String>) -> Vec<String> {
+    resolve_candidates(&value, &roots)
+        .into_iter()
+        .map(|path| path.to_string_lossy().to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod resolve_path_tests {
+    use super::*;
+    use crate::test_support::temp_dir;
+    use std::fs;
+
+    #[test]
+    fn resolve_candidates_returns_matches_from_every_root() {
+        let dir = temp_dir("resolve-multi-root");
+        let app = dir.join("app");
+        let lib = dir.join("lib");
+        let empty = dir.join("empty");
+        for root in [&app, &lib, &empty] {
+            fs::create_dir_all(root).expect("create root");
+        }
+        fs::create_dir_all(app.join("src")).expect("create app/src");
+        fs::create_dir_all(lib.join("src")).expect("create lib/src");
+        fs::write(app.join("src/main.rs"), "").expect("write app main");
+        fs::write(lib.join("src/main.rs"), "").expect("write lib main");
+
+        let roots = [&empty, &app, &lib]
+            .iter()
+            .map(|root| root.to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            resolve_candidates("src/main.rs", &roots),
+            vec![app.join("src/main.rs"), lib.join("src/main.rs")]
+        );
+
+        fs::remove_dir_all(dir).expect("remove temp dir");
+    }
+
+    #[test]
+    fn resolve_candidates_dedupes_roots_pointing_at_the_same_file() {
+        let dir = temp_dir("resolve-dedupe");
+        fs::write(dir.join("notes.md"), "").expect("write notes");
+
+        let root = dir.to_string_lossy().to_string();
+        let nested = dir.join("..").join(dir.file_name().expect("dir name"));
+        let roots = vec![root, nested.to_string_lossy().to_string()];
+        assert_eq!(
+            resolve_candidates("notes.md", &roots),
+            vec![dir.join("notes.md")]
+        );
+
+        fs::remove_dir_all(dir).expect("remove temp dir");
+    }
+
+    #[test]
+    fn resolve_candidates_keeps_existing_absolute_paths() {
+        let dir = temp_dir("resolve-absolute");
+        let file = dir.join("config.json");
+        fs::write(&file, "{}").expect("write config");
+
+        let value = file.to_string_lossy().to_string();
+        assert_eq!(resolve_candidates(&value, &[]), vec![file.clone()]);
+        let missing = dir.join("missing.json").to_string_lossy().to_string();
+        assert!(resolve_candidates(&missing, &[]).is_empty());
+
+        fs::remove_dir_all(dir).expect("remove temp dir");
+    }
+}
+
 fn is_safe_markdown_target_extension(path: &Path) -> bool {
     matches!(
         path.extension()
@@ -803,18 +1155,8 @@ fn open_safe_markdown_target(path: String) -> Result<(), String> {
 #[cfg(test)]
 mod markdown_target_tests {
     use super::*;
+    use crate::test_support::temp_dir;
     use std::fs;
-    use std::time::{SystemTime, UNIX_EPOCH};
-
-    fn temp_dir(name: &str) -> PathBuf {
-        let suffix = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("system clock")
-            .as_nanos();
-        let dir = env::temp_dir().join(format!("attn-{name}-{}-{suffix}", std::process::id()));
-        fs::create_dir_all(&dir).expect("create temp dir");
-        dir
-    }
 
     #[test]
     fn canonical_safe_markdown_target_accepts_regular_document_outside_home() {
@@ -887,10 +1229,6 @@ fn percent_encode_path(path: &str) -> String {
     encoded
 }
 
-fn looks_like_zed_editor(editor: &str) -> bool {
-    editor.to_ascii_lowercase().contains("zed")
-}
-
 fn build_remote_zed_target(remote_target: &str, cwd: &str, file_path: Option<&str>) -> String {
     let resolved = if let Some(path) = file_path.filter(|value| !value.trim().is_empty()) {
         let path_buf = Path::new(path);
@@ -915,6 +1253,15 @@ fn build_remote_zed_target(remote_target: &str, cwd: &str, file_path: Option<&st
     )
 }
 
+fn configured_editor(explicit: Option<String>) -> Option<String> {
+    explicit
+        .into_iter()
+        .chain(env::var("EDITOR").ok())
+        .chain(env::var("VISUAL").ok())
+        .map(|value| value.trim().to_string())
+        .find(|value| !value.is_empty())
+}
+
 #[tauri::command]
 fn open_in_editor(
     cwd: String,
@@ -922,12 +1269,8 @@ fn open_in_editor(
     editor: Option<String>,
     remote_target: Option<String>,
 ) -> Result<(), String> {
-    let editor = editor
-        .map(|value| value.trim().to_string())
-        .filter(|value| !value.is_empty())
-        .or_else(|| env::var("EDITOR").ok())
-        .or_else(|| env::var("VISUAL").ok())
-        .ok_or_else(|| "EDITOR (or VISUAL) is not set".to_string())?;
+    let editor =
+        configured_editor(editor).ok_or_else(|| "EDITOR (or VISUAL) is not set".to_string())?;
 
     let mut local_cwd: Option<PathBuf> = None;
     let mut args: Vec<String> = Vec::new();
@@ -935,7 +1278,8 @@ fn open_in_editor(
         .map(|value| value.trim().to_string())
         .filter(|value| !value.is_empty())
     {
-        if !looks_like_zed_editor(&editor) {
+        let program = classify_editor(Some(editor.clone())).program;
+        if !program.as_deref().is_some_and(is_zed_program) {
             return Err("Remote open-in-editor currently requires Zed.".to_string());
         }
         args.push(build_remote_zed_target(
@@ -1000,6 +1344,191 @@ fn open_in_editor(
     Ok(())
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+enum EditorKind {
+    Gui,
+    Terminal,
+    Unknown,
+}
+
+/// How an editor expects a line/column target on its command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+enum EditorLocationStyle {
+    /// `code --goto file:line:col`
+    GotoFlag,
+    /// `subl file:line:col`
+    ColonSuffix,
+    /// `vim +line file`
+    PlusLine,
+    /// No known convention; open the file without a location.
+    None,
+}
+
+#[derive(Debug, PartialEq, Eq, serde::Serialize)]
+struct EditorInfo {
+    command: Option<String>,
+    program: Option<String>,
+    kind: EditorKind,
+    location_style: EditorLocationStyle,
+}
+
+/// Lowercased basename of the program in an editor command line. A quoted
+/// first token may contain spaces (app bundle paths), Zed's bundled
+/// `Zed.app/Contents/MacOS/cli` is named after its bundle, and
+/// `flatpak run dev.zed.Zed` after the last segment of the app id.
+fn editor_program_name(command: &str) -> Option<String> {
+    let command = command.trim_start();
+    let program = match command.chars().next()? {
+        quote @ ('"' | '\'') => {
+            let rest = &command[1..];
+            &rest[..rest.find(quote).unwrap_or(rest.len())]
+        }
+        _ => command.split_whitespace().next()?,
+    };
+    let path = Path::new(program);
+    let mut name = path.file_name()?.to_str()?.to_ascii_lowercase();
+    if name == "cli" {
+        if let Some(bundle) = path
+            .ancestors()
+            .filter_map(|ancestor| ancestor.file_name()?.to_str())
+            .find_map(|component| component.strip_suffix(".app"))
+        {
+            name = bundle.to_ascii_lowercase();
+        }
+    } else if name == "flatpak" {
+        if let Some(app_id) = command
+            .split_whitespace()
+            .skip_while(|token| *token != "run")
+            .skip(1)
+            .find(|token| !token.starts_with('-'))
+        {
+            name = app_id
+                .rsplit('.')
+                .next()
+                .unwrap_or(app_id)
+                .to_ascii_lowercase();
+        }
+    }
+    Some(name.strip_suffix(".exe").unwrap_or(&name).to_string())
+}
+
+/// Zed ships under several names (`zed`, `zeditor`, `zed-editor`, `zedit`,
+/// `zed-preview`, the `Zed Preview.app` bundle), so match on the prefix.
+fn is_zed_program(program: &str) -> bool {
+    program.starts_with("zed")
+}
+
+fn classify_editor(command: Option<String>) -> EditorInfo {
+    let program = command.as_deref().and_then(editor_program_name);
+    let (kind, location_style) = match program.as_deref() {
+        Some("code" | "code-insiders" | "codium" | "cursor" | "windsurf") => {
+            (EditorKind::Gui, EditorLocationStyle::GotoFlag)
+        }
+        Some(program) if program == "subl" || is_zed_program(program) => {
+            (EditorKind::Gui, EditorLocationStyle::ColonSuffix)
+        }
+        Some("vi" | "vim" | "nvim" | "nano" | "emacs" | "micro" | "kak") => {
+            (EditorKind::Terminal, EditorLocationStyle::PlusLine)
+        }
+        Some("hx" | "helix") => (EditorKind::Terminal, EditorLocationStyle::ColonSuffix),
+        _ => (EditorKind::Unknown, EditorLocationStyle::None),
+    };
+    EditorInfo {
+        command,
+        program,
+        kind,
+        location_style,
+    }
+}
+
+/// Reports the editor `open_in_editor` would launch for the same `editor`
+/// argument (the configured executable, then EDITOR/VISUAL) and whether it
+/// needs a terminal, so the frontend can choose between a new session and
+/// handing the file to a GUI app.
+#[tauri::command]
+fn detect_editor(editor: Option<String>) -> EditorInfo {
+    classify_editor(configured_editor(editor))
+}
+
+#[cfg(test)]
+mod detect_editor_tests {
+    use super::*;
+
+    #[test]
+    fn classify_editor_recognizes_gui_editors() {
+        let info = classify_editor(Some("code --wait".to_string()));
+        assert_eq!(info.program.as_deref(), Some("code"));
+        assert_eq!(info.kind, EditorKind::Gui);
+        assert_eq!(info.location_style, EditorLocationStyle::GotoFlag);
+
+        let info = classify_editor(Some("/usr/local/bin/zed".to_string()));
+        assert_eq!(info.kind, EditorKind::Gui);
+        assert_eq!(info.location_style, EditorLocationStyle::ColonSuffix);
+    }
+
+    #[test]
+    fn classify_editor_handles_quoted_app_bundle_paths() {
+        let info = classify_editor(Some(
+            "\"/Applications/Sublime Text.app/Contents/SharedSupport/bin/subl\" -w".to_string(),
+        ));
+        assert_eq!(info.program.as_deref(), Some("subl"));
+        assert_eq!(info.kind, EditorKind::Gui);
+
+        let info = classify_editor(Some(
+            "'/Applications/Zed.app/Contents/MacOS/cli'".to_string(),
+        ));
+        assert_eq!(info.program.as_deref(), Some("zed"));
+        assert_eq!(info.location_style, EditorLocationStyle::ColonSuffix);
+    }
+
+    #[test]
+    fn classify_editor_recognizes_packaged_zed_commands() {
+        for command in [
+            "flatpak run dev.zed.Zed",
+            "zed-editor --wait",
+            "zedit",
+            "/usr/bin/zed-preview",
+        ] {
+            let info = classify_editor(Some(command.to_string()));
+            assert!(
+                info.program.as_deref().is_some_and(is_zed_program),
+                "{command} should be detected as Zed, got {:?}",
+                info.program
+            );
+            assert_eq!(info.location_style, EditorLocationStyle::ColonSuffix);
+        }
+    }
+
+    #[test]
+    fn classify_editor_recognizes_terminal_editors() {
+        let info = classify_editor(Some("nvim".to_string()));
+        assert_eq!(info.kind, EditorKind::Terminal);
+        assert_eq!(info.location_style, EditorLocationStyle::PlusLine);
+
+        let info = classify_editor(Some("hx".to_string()));
+        assert_eq!(info.kind, EditorKind::Terminal);
+        assert_eq!(info.location_style, EditorLocationStyle::ColonSuffix);
+    }
+
+    #[test]
+    fn classify_editor_defaults_when_unset_or_unknown() {
+        assert_eq!(
+            classify_editor(None),
+            EditorInfo {
+                command: None,
+                program: None,
+                kind: EditorKind::Unknown,
+                location_style: EditorLocationStyle::None,
+            }
+        );
+        let info = classify_editor(Some("ed".to_string()));
+        assert_eq!(info.kind, EditorKind::Unknown);
+        assert_eq!(info.location_style, EditorLocationStyle::None);
+    }
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     // Must run before anything reads ATTN_PROFILE / ATTN_WS_PORT (including
@@ -1081,9 +1610,15 @@ Object.defineProperty(window, "__ATTN_NATIVE_DIALOGS", {
         })
         .invoke_handler(tauri::generate_handler![
             list_directory,
+            compute_grid,
+            is_git_repo,
+            session_color,
+            resolve_path,
             ensure_daemon,
+            daemon_ping,
             quit_app,
             open_in_editor,
+            detect_editor,
             open_safe_markdown_target,
             get_build_profile,
             get_browser_host_token,
@@ -78,7 +78,7 @@ pub fn apply_build_profile_env() {
 }
 
 fn data_dir() -> Result<PathBuf, String> {
-    let home = dirs::home_dir().ok_or_else(|| "home directory is unavailable".to_string())?;
+    let home = crate::home::home_dir()?;
     let name = match build_profile() {
         "" => ".attn".to_string(),
         profile => format!(".attn-{profile}"),
@@ -0,0 +1,29 @@
+//! Scratch-path helpers shared by the crate's test modules.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn unique_name(name: &str) -> String {
+    let suffix = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock")
+        .as_nanos();
+    format!("attn-{name}-{}-{suffix}", std::process::id())
+}
+
+/// Creates a unique directory under the system temp dir and returns its
+/// canonical path, so assertions against canonicalized paths match on macOS
+/// where `/var` is a symlink to `/private/var`.
+pub(crate) fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(unique_name(name));
+    fs::create_dir_all(&dir).expect("create temp dir");
+    fs::canonicalize(&dir).expect("canonical temp dir")
+}
+
+/// Returns a unique path with extension `ext` under the system temp dir
+/// without creating anything there.
+pub(crate) fn temp_path(name: &str, ext: &str) -> PathBuf {
+    env::temp_dir().join(format!("{}.{ext}", unique_name(name)))
+}
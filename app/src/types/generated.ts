@@ -862,10 +862,12 @@ export enum BranchesResultMessageEvent {
 }
 
 export interface BrowseDirectoryMessage {
-    cmd:          BrowseDirectoryMessageCmd;
-    endpoint_id?: string;
-    input_path:   string;
-    request_id?:  string;
+    cmd:               BrowseDirectoryMessageCmd;
+    endpoint_id?:      string;
+    include_excluded?: boolean;
+    input_path:        string;
+    request_id?:       string;
+    sort?:             string;
     [property: string]: any;
 }
 
@@ -9036,8 +9038,10 @@ const typeMap: any = {
     "BrowseDirectoryMessage": o([
         { json: "cmd", js: "cmd", typ: r("BrowseDirectoryMessageCmd") },
         { json: "endpoint_id", js: "endpoint_id", typ: u(undefined, "") },
+        { json: "include_excluded", js: "include_excluded", typ: u(undefined, true) },
         { json: "input_path", js: "input_path", typ: "" },
         { json: "request_id", js: "request_id", typ: u(undefined, "") },
+        { json: "sort", js: "sort", typ: u(undefined, "") },
     ], "any"),
     "BrowseDirectoryResultMessage": o([
         { json: "directory", js: "directory", typ: "" },
@@ -1,11 +1,15 @@
 package daemon
 
 import (
+	"fmt"
 	"os"
 	"path/filepath"
 	"sort"
 	"strings"
+	"time"
 
+	"github.com/BurntSushi/toml"
+	"github.com/victorarias/attn/internal/config"
 	"github.com/victorarias/attn/internal/git"
 	"github.com/victorarias/attn/internal/protocol"
 )
@@ -17,18 +21,65 @@ func expandPickerPath(input string) (resolved string, homePath string, err error
 	}
 
 	trimmed := strings.TrimSpace(input)
-	switch {
-	case trimmed == "":
+	if trimmed == "" {
 		return "", homePath, nil
-	case trimmed == "~":
+	}
+
+	expanded, err := expandPickerEnv(trimmed)
+	if err != nil {
+		return "", homePath, err
+	}
+
+	switch {
+	case expanded == "~":
 		return homePath, homePath, nil
-	case strings.HasPrefix(trimmed, "~/"):
-		return filepath.Join(homePath, trimmed[2:]), homePath, nil
-	case strings.HasPrefix(trimmed, "~"):
-		return filepath.Join(homePath, trimmed[1:]), homePath, nil
+	case strings.HasPrefix(expanded, "~/"):
+		return filepath.Join(homePath, expanded[2:]), homePath, nil
+	case strings.HasPrefix(expanded, "~"):
+		return filepath.Join(homePath, expanded[1:]), homePath, nil
+	case filepath.IsAbs(expanded):
+		return expanded, homePath, nil
 	default:
-		return trimmed, homePath, nil
+		// The picker has no working directory of its own, so relative paths
+		// are taken against home, the same base the UI displays paths against.
+		return filepath.Join(homePath, expanded), homePath, nil
+	}
+}
+
+// expandPickerEnv expands a leading $VAR or ${VAR}. An unset variable is an
+// error rather than a literal directory name.
+func expandPickerEnv(input string) (string, error) {
+	if !strings.HasPrefix(input, "$") {
+		return input, nil
+	}
+
+	var name, rest string
+	if strings.HasPrefix(input, "${") {
+		end := strings.IndexByte(input, '}')
+		if end == -1 {
+			return input, nil
+		}
+		name, rest = input[2:end], input[end+1:]
+	} else {
+		end := 1
+		for end < len(input) && isEnvNameByte(input[end]) {
+			end++
+		}
+		name, rest = input[1:end], input[end:]
+	}
+	if name == "" {
+		return input, nil
+	}
+
+	value, ok := os.LookupEnv(name)
+	if !ok || value == "" {
+		return "", fmt.Errorf("environment variable %s is not set", name)
 	}
+	return value + rest, nil
+}
+
+func isEnvNameByte(c byte) bool {
+	return c == '_' || 'a' <= c && c <= 'z' || 'A' <= c && c <= 'Z' || '0' <= c && c <= '9'
 }
 
 func parseBrowseInput(input string) (directory string, prefix string, homePath string, err error) {
@@ -56,7 +107,59 @@ func parseBrowseInput(input string) (directory string, prefix string, homePath s
 	return filepath.Clean(directory), strings.ToLower(expanded[lastSlash+1:]), homePath, nil
 }
 
-func listDirectoryEntries(dirToQuery string, prefix string) ([]protocol.DirectoryEntry, error) {
+// pickerConfigName is the optional per-profile picker config in the data dir.
+const pickerConfigName = "picker.toml"
+
+// defaultPickerExcludes are directory names hidden from browse_directory
+// listings unless the request sets include_excluded.
+var defaultPickerExcludes = []string{"node_modules", ".venv", "target", "__pycache__"}
+
+type pickerConfig struct {
+	Exclude []string `toml:"exclude"`
+}
+
+// loadPickerExcludes returns the default excludes plus the names listed under
+// `exclude` in <dataDir>/picker.toml. The defaults are always returned, even
+// when the file is missing or fails to parse.
+func loadPickerExcludes(dataDir string) (map[string]struct{}, error) {
+	excludes := make(map[string]struct{}, len(defaultPickerExcludes))
+	for _, name := range defaultPickerExcludes {
+		excludes[name] = struct{}{}
+	}
+
+	var cfg pickerConfig
+	path := filepath.Join(dataDir, pickerConfigName)
+	if _, err := toml.DecodeFile(path, &cfg); err != nil {
+		if os.IsNotExist(err) {
+			return excludes, nil
+		}
+		return excludes, fmt.Errorf("read %s: %w", path, err)
+	}
+	for _, name := range cfg.Exclude {
+		if name = strings.TrimSpace(name); name != "" {
+			excludes[name] = struct{}{}
+		}
+	}
+	return excludes, nil
+}
+
+// Values accepted in BrowseDirectoryMessage.Sort. Empty means name.
+const (
+	pickerSortName  = "name"
+	pickerSortMtime = "mtime"
+)
+
+// listDirectoryEntries lists the subdirectories of dirToQuery whose name
+// contains prefix, skipping any name in excludes (exact basename match).
+// Prefix matches rank first; within each group entries are ordered by name,
+// or most recently modified first when sortBy is "mtime".
+func listDirectoryEntries(dirToQuery string, prefix string, excludes map[string]struct{}, sortBy string) ([]protocol.DirectoryEntry, error) {
+	switch sortBy {
+	case "", pickerSortName, pickerSortMtime:
+	default:
+		return nil, fmt.Errorf("unknown sort %q (want %q or %q)", sortBy, pickerSortName, pickerSortMtime)
+	}
+
 	entries, err := os.ReadDir(dirToQuery)
 	if err != nil {
 		return nil, err
@@ -64,12 +167,16 @@ func listDirectoryEntries(dirToQuery string, prefix string) ([]protocol.Director
 
 	prefix = strings.ToLower(prefix)
 	var directories []protocol.DirectoryEntry
+	modTimes := make(map[string]time.Time, len(entries))
 	for _, entry := range entries {
 		info, err := entry.Info()
 		if err != nil || !info.IsDir() {
 			continue
 		}
 		name := entry.Name()
+		if _, excluded := excludes[name]; excluded {
+			continue
+		}
 		if prefix != "" && !strings.Contains(strings.ToLower(name), prefix) {
 			continue
 		}
@@ -77,6 +184,7 @@ func listDirectoryEntries(dirToQuery string, prefix string) ([]protocol.Director
 			Name: name,
 			Path: filepath.Join(dirToQuery, name),
 		})
+		modTimes[name] = info.ModTime()
 	}
 
 	sort.Slice(directories, func(i, j int) bool {
@@ -89,6 +197,13 @@ func listDirectoryEntries(dirToQuery string, prefix string) ([]protocol.Director
 				return leftStarts
 			}
 		}
+		if sortBy == pickerSortMtime {
+			leftTime := modTimes[directories[i].Name]
+			rightTime := modTimes[directories[j].Name]
+			if !leftTime.Equal(rightTime) {
+				return leftTime.After(rightTime)
+			}
+		}
 		return left < right
 	})
 
@@ -161,7 +276,15 @@ func (d *Daemon) handleBrowseDirectoryWS(client *wsClient, msg *protocol.BrowseD
 			return
 		}
 
-		entries, err := listDirectoryEntries(dirToQuery, prefix)
+		var excludes map[string]struct{}
+		if !protocol.Deref(msg.IncludeExcluded) {
+			excludes, err = loadPickerExcludes(config.DataDir())
+			if err != nil {
+				d.logf("browse_directory: %v", err)
+			}
+		}
+
+		entries, err := listDirectoryEntries(dirToQuery, prefix, excludes, protocol.Deref(msg.Sort))
 		if err != nil {
 			d.sendToClient(client, &protocol.BrowseDirectoryResultMessage{
 				Event:      protocol.EventBrowseDirectoryResult,
@@ -4,7 +4,9 @@ import (
 	"os"
 	"os/exec"
 	"path/filepath"
+	"strings"
 	"testing"
+	"time"
 )
 
 func sameDirectory(left string, right string) bool {
@@ -71,6 +73,92 @@ func TestParseBrowseInputUsesParentDirectoryForPartialChildMatch(t *testing.T) {
 	}
 }
 
+func TestExpandPickerPathExpandsLeadingEnvVar(t *testing.T) {
+	base := t.TempDir()
+	t.Setenv("ATTN_PICKER_TEST_DIR", base)
+
+	for _, input := range []string{"$ATTN_PICKER_TEST_DIR/projects", "${ATTN_PICKER_TEST_DIR}/projects"} {
+		resolved, _, err := expandPickerPath(input)
+		if err != nil {
+			t.Fatalf("expandPickerPath(%q) error = %v", input, err)
+		}
+		want := filepath.Join(base, "projects")
+		if resolved != want {
+			t.Fatalf("expandPickerPath(%q) = %q, want %q", input, resolved, want)
+		}
+	}
+}
+
+func TestExpandPickerPathExpandsHomeEnvVar(t *testing.T) {
+	home, err := os.UserHomeDir()
+	if err != nil {
+		t.Fatalf("UserHomeDir() error = %v", err)
+	}
+
+	resolved, _, err := expandPickerPath("$HOME/projects")
+	if err != nil {
+		t.Fatalf("expandPickerPath() error = %v", err)
+	}
+	want := filepath.Join(home, "projects")
+	if resolved != want {
+		t.Fatalf("resolved = %q, want %q", resolved, want)
+	}
+}
+
+func TestExpandPickerPathResolvesRelativeResultsAgainstHome(t *testing.T) {
+	home, err := os.UserHomeDir()
+	if err != nil {
+		t.Fatalf("UserHomeDir() error = %v", err)
+	}
+	t.Setenv("ATTN_PICKER_TEST_TILDE", "~/code")
+	t.Setenv("ATTN_PICKER_TEST_RELATIVE", "code")
+
+	for input, want := range map[string]string{
+		"projects/foo":                   filepath.Join(home, "projects", "foo"),
+		"$ATTN_PICKER_TEST_TILDE/foo":    filepath.Join(home, "code", "foo"),
+		"${ATTN_PICKER_TEST_RELATIVE}/x": filepath.Join(home, "code", "x"),
+	} {
+		resolved, _, err := expandPickerPath(input)
+		if err != nil {
+			t.Fatalf("expandPickerPath(%q) error = %v", input, err)
+		}
+		if resolved != want {
+			t.Fatalf("expandPickerPath(%q) = %q, want %q", input, resolved, want)
+		}
+	}
+}
+
+func TestExpandPickerPathRejectsUnsetEnvVar(t *testing.T) {
+	t.Setenv("ATTN_PICKER_TEST_UNSET", "")
+	if err := os.Unsetenv("ATTN_PICKER_TEST_UNSET"); err != nil {
+		t.Fatalf("Unsetenv() error = %v", err)
+	}
+
+	if _, _, err := expandPickerPath("$ATTN_PICKER_TEST_UNSET/projects"); err == nil {
+		t.Fatal("expandPickerPath() error = nil, want unset variable error")
+	}
+}
+
+func TestParseBrowseInputResolvesDotDotAgainstHome(t *testing.T) {
+	home, err := os.UserHomeDir()
+	if err != nil {
+		t.Fatalf("UserHomeDir() error = %v", err)
+	}
+
+	directory, prefix, _, err := parseBrowseInput("../sib")
+	if err != nil {
+		t.Fatalf("parseBrowseInput() error = %v", err)
+	}
+
+	wantDirectory := filepath.Dir(home)
+	if directory != wantDirectory {
+		t.Fatalf("directory = %q, want %q", directory, wantDirectory)
+	}
+	if prefix != "sib" {
+		t.Fatalf("prefix = %q, want %q", prefix, "sib")
+	}
+}
+
 func TestInspectPickerPathTreatsSlashVariantsTheSameForRepoRoots(t *testing.T) {
 	tmpDir := t.TempDir()
 	repoDir := filepath.Join(tmpDir, "repo")
@@ -181,3 +269,91 @@ func TestInspectPickerPathCanonicalizesSymlinkedRepoRoots(t *testing.T) {
 		t.Fatalf("repo root = %v, want same directory as %q", inspection.RepoRoot, realRepoDir)
 	}
 }
+
+func TestListDirectoryEntriesSkipsExcludedNamesUnlessBypassed(t *testing.T) {
+	dir := t.TempDir()
+	for _, name := range []string{"node_modules", "src"} {
+		if err := os.Mkdir(filepath.Join(dir, name), 0o755); err != nil {
+			t.Fatalf("mkdir %s: %v", name, err)
+		}
+	}
+
+	excludes, err := loadPickerExcludes(t.TempDir())
+	if err != nil {
+		t.Fatalf("loadPickerExcludes() error = %v", err)
+	}
+	entries, err := listDirectoryEntries(dir, "", excludes, "")
+	if err != nil {
+		t.Fatalf("listDirectoryEntries() error = %v", err)
+	}
+	if len(entries) != 1 || entries[0].Name != "src" {
+		t.Fatalf("entries = %+v, want only src", entries)
+	}
+
+	entries, err = listDirectoryEntries(dir, "", nil, "")
+	if err != nil {
+		t.Fatalf("listDirectoryEntries() error = %v", err)
+	}
+	if len(entries) != 2 {
+		t.Fatalf("entries = %+v, want node_modules and src", entries)
+	}
+}
+
+func TestLoadPickerExcludesAddsPickerTomlNamesToDefaults(t *testing.T) {
+	dataDir := t.TempDir()
+	if err := os.WriteFile(filepath.Join(dataDir, pickerConfigName), []byte("exclude = [\"build\", \" \"]\n"), 0o644); err != nil {
+		t.Fatalf("write picker.toml: %v", err)
+	}
+
+	excludes, err := loadPickerExcludes(dataDir)
+	if err != nil {
+		t.Fatalf("loadPickerExcludes() error = %v", err)
+	}
+	for _, name := range []string{"build", "node_modules", "target"} {
+		if _, ok := excludes[name]; !ok {
+			t.Fatalf("excludes missing %q: %v", name, excludes)
+		}
+	}
+	if _, ok := excludes[""]; ok {
+		t.Fatalf("excludes contains blank name: %v", excludes)
+	}
+}
+
+func TestListDirectoryEntriesSortsByMtimeWhenRequested(t *testing.T) {
+	dir := t.TempDir()
+	now := time.Now()
+	for i, name := range []string{"alpha", "beta", "gamma"} {
+		path := filepath.Join(dir, name)
+		if err := os.Mkdir(path, 0o755); err != nil {
+			t.Fatalf("mkdir %s: %v", name, err)
+		}
+		modTime := now.Add(time.Duration(i) * time.Hour)
+		if err := os.Chtimes(path, modTime, modTime); err != nil {
+			t.Fatalf("chtimes %s: %v", name, err)
+		}
+	}
+
+	entries, err := listDirectoryEntries(dir, "", nil, pickerSortMtime)
+	if err != nil {
+		t.Fatalf("listDirectoryEntries() error = %v", err)
+	}
+	var names []string
+	for _, entry := range entries {
+		names = append(names, entry.Name)
+	}
+	if got, want := strings.Join(names, ","), "gamma,beta,alpha"; got != want {
+		t.Fatalf("mtime order = %q, want %q", got, want)
+	}
+
+	entries, err = listDirectoryEntries(dir, "", nil, "")
+	if err != nil {
+		t.Fatalf("listDirectoryEntries() error = %v", err)
+	}
+	if entries[0].Name != "alpha" {
+		t.Fatalf("default order starts with %q, want alpha", entries[0].Name)
+	}
+
+	if _, err := listDirectoryEntries(dir, "", nil, "size"); err == nil {
+		t.Fatal("listDirectoryEntries() error = nil, want unknown sort error")
+	}
+}
@@ -537,11 +537,17 @@ type BrowseDirectoryMessage struct {
 	// EndpointID corresponds to the JSON schema field "endpoint_id".
 	EndpointID *string `json:"endpoint_id,omitempty,omitzero"`
 
+	// IncludeExcluded corresponds to the JSON schema field "include_excluded".
+	IncludeExcluded *bool `json:"include_excluded,omitempty,omitzero"`
+
 	// InputPath corresponds to the JSON schema field "input_path".
 	InputPath string `json:"input_path"`
 
 	// RequestID corresponds to the JSON schema field "request_id".
 	RequestID *string `json:"request_id,omitempty,omitzero"`
+
+	// Sort corresponds to the JSON schema field "sort".
+	Sort *string `json:"sort,omitempty,omitzero"`
 }
 
 type BrowseDirectoryResultMessage struct {